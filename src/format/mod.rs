@@ -0,0 +1,56 @@
+pub mod replayer;
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::parse::Hand;
+
+/// The shape the parsed hands are serialized into.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum OutputFormat {
+    /// The raw, internal `Hand` representation as recorded by the parser.
+    Internal,
+
+    /// A street-by-street game log suited to a web hand replayer.
+    Replayer,
+}
+
+#[derive(Clone, Debug)]
+pub struct OutputFormatParseError(String);
+
+impl fmt::Display for OutputFormatParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unknown output format: {}", self.0)
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = OutputFormatParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "internal" => Ok(OutputFormat::Internal),
+            "replayer" => Ok(OutputFormat::Replayer),
+            _ => Err(OutputFormatParseError(String::from(s))),
+        }
+    }
+}
+
+/// Serializes the parsed hands in the requested output format.
+pub fn serialize(hands: &[Hand], format: OutputFormat) -> serde_json::Value {
+    match format {
+        OutputFormat::Internal => to_json(hands),
+        OutputFormat::Replayer => {
+            let hands: Vec<replayer::ReplayerHand> =
+                hands.iter().map(replayer::ReplayerHand::from).collect();
+
+            to_json(&hands)
+        }
+    }
+}
+
+fn to_json<T: Serialize + ?Sized>(value: &T) -> serde_json::Value {
+    serde_json::to_value(value).unwrap()
+}
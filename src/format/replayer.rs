@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::parse::{Action, Amount, Card, Game, Hand};
+
+/// A hand rendered as a street-by-street game log, suited to a web hand
+/// replayer front end rather than the raw internal `Hand` representation.
+#[derive(Clone, Debug, Serialize)]
+pub struct ReplayerHand {
+    pub game: Game,
+    pub stake: Amount,
+    pub seats: HashMap<u8, ReplayerSeat>,
+    pub streets: Vec<ReplayerStreet>,
+    pub pot: Amount,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ReplayerSeat {
+    pub player: String,
+    pub starting_stack: Amount,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Street {
+    PreFlop,
+    Flop,
+    Turn,
+    River,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ReplayerStreet {
+    pub street: Street,
+    pub board: Vec<Card>,
+    pub actions: Vec<Action>,
+}
+
+impl From<&Hand> for ReplayerHand {
+    fn from(hand: &Hand) -> ReplayerHand {
+        let seats = hand
+            .seats
+            .iter()
+            .map(|seat| {
+                let replayer_seat = ReplayerSeat {
+                    player: seat.player_id.clone(),
+                    starting_stack: seat.stack,
+                };
+
+                (seat.number, replayer_seat)
+            })
+            .collect();
+
+        let mut streets = vec![ReplayerStreet {
+            street: Street::PreFlop,
+            board: vec![],
+            actions: vec![],
+        }];
+
+        let mut pot = Amount {
+            integer: 0,
+            fraction: 0,
+        };
+
+        for action in &hand.actions {
+            match action {
+                Action::Flop { cards } => {
+                    let mut board = streets.last().unwrap().board.clone();
+                    board.extend_from_slice(cards);
+
+                    streets.push(ReplayerStreet {
+                        street: Street::Flop,
+                        board,
+                        actions: vec![action.clone()],
+                    });
+                }
+                Action::Turn { card } => {
+                    let mut board = streets.last().unwrap().board.clone();
+                    board.push(card.clone());
+
+                    streets.push(ReplayerStreet {
+                        street: Street::Turn,
+                        board,
+                        actions: vec![action.clone()],
+                    });
+                }
+                Action::River { card } => {
+                    let mut board = streets.last().unwrap().board.clone();
+                    board.push(card.clone());
+
+                    streets.push(ReplayerStreet {
+                        street: Street::River,
+                        board,
+                        actions: vec![action.clone()],
+                    });
+                }
+                Action::Collect { amount, .. } => {
+                    pot = pot.add(*amount);
+                    streets.last_mut().unwrap().actions.push(action.clone());
+                }
+                _ => {
+                    streets.last_mut().unwrap().actions.push(action.clone());
+                }
+            }
+        }
+
+        ReplayerHand {
+            game: hand.game.clone(),
+            stake: hand.stake,
+            seats,
+            streets,
+            pot,
+        }
+    }
+}
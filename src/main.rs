@@ -1,5 +1,7 @@
+mod format;
 mod parse;
 
+use format::OutputFormat;
 use parse::{Action, Game};
 
 use clap::{App, Arg};
@@ -28,6 +30,16 @@ fn main() {
                 .takes_value(true)
                 .help("Output file"),
         )
+        .arg(
+            Arg::with_name("format")
+                .required(false)
+                .default_value("internal")
+                .possible_values(&["internal", "replayer"])
+                .short("f")
+                .long("format")
+                .takes_value(true)
+                .help("Output format"),
+        )
         .arg(
             Arg::with_name("stats")
                 .required(false)
@@ -44,15 +56,26 @@ fn main() {
                 .takes_value(false)
                 .help("Prints debug info"),
         )
+        .arg(
+            Arg::with_name("unicode-suits")
+                .required(false)
+                .long("unicode-suits")
+                .takes_value(false)
+                .help("Prints suits as Unicode glyphs (♣ ♦ ♥ ♠) instead of letters"),
+        )
         .get_matches();
 
     let input = matches.value_of("input").unwrap();
     let output = matches.value_of("output").unwrap();
+    let format = matches.value_of("format").unwrap();
+    let format = format.parse::<OutputFormat>().unwrap();
     let stats = matches.is_present("stats");
     let debug = matches.is_present("debug");
 
+    parse::set_unicode_suits(matches.is_present("unicode-suits"));
+
     let hands = parse::parse(input);
-    let json = serde_json::to_value(&hands).unwrap();
+    let json = format::serialize(&hands, format);
     let json = serde_json::to_string(&json).unwrap();
 
     std::fs::write(output, json).unwrap();
@@ -64,42 +87,71 @@ fn main() {
     if stats {
         println!("Hands: {}", hands.len());
 
+        let player_stats = parse::stats::aggregate(&hands);
+
         let mut flops = 0;
         let mut turns = 0;
         let mut rivers = 0;
         let mut shows = 0;
+        let mut winners = 0;
 
         let mut unknown = 0;
         let mut nlh = 0;
         let mut nlh_he = 0;
+        let mut plo = 0;
+        let mut plo_he = 0;
 
         for hand in hands {
+            if !hand.showdown.is_empty() {
+                winners += 1;
+            }
+
             match hand.game {
                 Game::Unknown(_) => unknown += 1,
+                Game::NoLimitHoldem => nlh += 1,
                 Game::NoLimitHoldemHeadsUp => nlh_he += 1,
-                Game::NoLimitHoldem => {
-                    nlh += 1;
-
-                    for action in hand.actions {
-                        match action {
-                            Action::Flop(_, _, _) => flops += 1,
-                            Action::Turn(_) => turns += 1,
-                            Action::River(_) => rivers += 1,
-                            Action::Show(_, _, _) => {
-                                shows += 1;
-                                break;
-                            }
-                            _ => (),
-                        }
+                Game::PotLimitOmaha => plo += 1,
+                Game::PotLimitOmahaHeadsUp => plo_he += 1,
+            };
+
+            for action in hand.actions {
+                match action {
+                    Action::Flop { .. } => flops += 1,
+                    Action::Turn { .. } => turns += 1,
+                    Action::River { .. } => rivers += 1,
+                    Action::Show { .. } => {
+                        shows += 1;
+                        break;
                     }
+                    _ => (),
                 }
-            };
+            }
         }
 
-        println!("NLH: {}\nNLH HE: {}\nUnknown: {}", nlh, nlh_he, unknown);
         println!(
-            "Flops: {}\nTurns: {}\nRivers: {}\nShowdowns: {}",
-            flops, turns, rivers, shows
+            "NLH: {}\nNLH HE: {}\nPLO: {}\nPLO HE: {}\nUnknown: {}",
+            nlh, nlh_he, plo, plo_he, unknown
+        );
+        println!(
+            "Flops: {}\nTurns: {}\nRivers: {}\nShowdowns: {}\nWinners: {}",
+            flops, turns, rivers, shows, winners
         );
+
+        println!("\nPlayer stats:");
+
+        let mut player_stats: Vec<_> = player_stats.values().collect();
+        player_stats.sort_by(|a, b| a.player_id.cmp(&b.player_id));
+
+        for player in player_stats {
+            println!(
+                "{}: hands={} vpip={:.1}% pfr={:.1}% showdowns={} net={}",
+                player.player_id,
+                player.hands_played,
+                player.vpip(),
+                player.pfr(),
+                player.showdowns_reached,
+                player.net_cents
+            );
+        }
     }
 }
@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Action, Card, Game, Hand};
+
+/// The category of a five-card poker hand, ordered weakest to strongest so
+/// `HandRank`s can be compared directly with `<`/`>`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum HandRank {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+/// A player's best five-card holding at showdown.
+///
+/// `kickers` holds the descending rank values used to break ties between
+/// two hands that share a `HandRank` (e.g. two pairs of Kings are broken by
+/// the next highest cards).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Showdown {
+    pub player_id: String,
+    pub rank: HandRank,
+    pub kickers: Vec<u8>,
+}
+
+impl Showdown {
+    fn strength(&self) -> (HandRank, &Vec<u8>) {
+        (self.rank, &self.kickers)
+    }
+}
+
+/// Replays a hand's dealt board and any `Show` actions to determine, for
+/// each player who showed their cards, the best five-card hand they can
+/// make. The strongest `Showdown` is first.
+pub fn evaluate(hand: &Hand) -> Vec<Showdown> {
+    let mut board = Vec::new();
+    let mut shows = Vec::new();
+
+    for action in &hand.actions {
+        match action {
+            Action::Flop { cards } => board.extend_from_slice(cards),
+            Action::Turn { card } => board.push(card.clone()),
+            Action::River { card } => board.push(card.clone()),
+            Action::Show { player, cards } => {
+                shows.push((player.clone(), cards.clone()));
+            }
+            _ => (),
+        }
+    }
+
+    let is_omaha = matches!(
+        hand.game,
+        Game::PotLimitOmaha | Game::PotLimitOmahaHeadsUp
+    );
+
+    let mut showdown: Vec<Showdown> = shows
+        .into_iter()
+        .filter_map(|(player_id, hole_cards)| {
+            let result = if is_omaha {
+                best_hand_constrained(&hole_cards, &board)
+            } else {
+                let mut available = board.clone();
+                available.extend(hole_cards);
+
+                best_hand(&available)
+            };
+
+            result.map(|(rank, kickers)| Showdown {
+                player_id,
+                rank,
+                kickers,
+            })
+        })
+        .collect();
+
+    showdown.sort_by(|a, b| b.strength().cmp(&a.strength()));
+
+    showdown
+}
+
+// Finds the best five-card hand achievable from the given cards (2 to 7 of
+// them). Returns `None` if fewer than five cards are available to classify.
+fn best_hand(cards: &[Card]) -> Option<(HandRank, Vec<u8>)> {
+    if cards.len() < 5 {
+        return None;
+    }
+
+    combinations(cards, 5)
+        .iter()
+        .map(|combo| classify(combo))
+        .max_by(|a, b| a.cmp(b))
+}
+
+// Finds the best five-card hand under the Omaha constraint: exactly two
+// hole cards plus exactly three board cards, rather than any five of the
+// combined cards. Returns `None` if there aren't enough hole or board
+// cards to satisfy that constraint.
+fn best_hand_constrained(hole: &[Card], board: &[Card]) -> Option<(HandRank, Vec<u8>)> {
+    if hole.len() < 2 || board.len() < 3 {
+        return None;
+    }
+
+    combinations(hole, 2)
+        .iter()
+        .flat_map(|hole_pair| {
+            combinations(board, 3).into_iter().map(move |board_triple| {
+                let mut five = hole_pair.clone();
+                five.extend(board_triple);
+
+                classify(&five)
+            })
+        })
+        .max_by(|a, b| a.cmp(b))
+}
+
+// All k-sized combinations of the given cards, order of selection ignored.
+fn combinations(cards: &[Card], k: usize) -> Vec<Vec<Card>> {
+    if k == 0 {
+        return vec![vec![]];
+    }
+
+    if cards.len() < k {
+        return vec![];
+    }
+
+    let mut result = Vec::new();
+
+    for (i, card) in cards.iter().enumerate() {
+        for mut rest in combinations(&cards[i + 1..], k - 1) {
+            rest.insert(0, card.clone());
+            result.push(rest);
+        }
+    }
+
+    result
+}
+
+// Classifies exactly five cards into a `HandRank` plus descending kickers
+// used to break ties against another hand of the same rank.
+fn classify(cards: &[Card]) -> (HandRank, Vec<u8>) {
+    let mut rank_counts: HashMap<u8, u8> = HashMap::new();
+    let mut suit_counts: HashMap<&super::Suit, u8> = HashMap::new();
+
+    for card in cards {
+        let value = card.rank.value();
+        *rank_counts.entry(value).or_insert(0) += 1;
+        *suit_counts.entry(&card.suit).or_insert(0) += 1;
+    }
+
+    let is_flush = suit_counts.values().any(|&count| count >= 5);
+
+    let mut distinct_values: Vec<u8> = rank_counts.keys().cloned().collect();
+    distinct_values.sort_unstable();
+
+    let straight_high = straight_high_card(&distinct_values);
+    let is_straight = straight_high.is_some();
+
+    // Descending (value, count) pairs, sorted by count then value so the
+    // most significant groups (quads, trips, pairs) come first.
+    let mut groups: Vec<(u8, u8)> = rank_counts.into_iter().collect();
+    groups.sort_by(|a, b| b.1.cmp(&a.1).then(b.0.cmp(&a.0)));
+
+    let counts: Vec<u8> = groups.iter().map(|&(_, count)| count).collect();
+    let kickers: Vec<u8> = groups.iter().map(|&(value, _)| value).collect();
+
+    if is_flush && is_straight {
+        return (HandRank::StraightFlush, vec![straight_high.unwrap()]);
+    }
+
+    if counts == [4, 1] {
+        return (HandRank::FourOfAKind, kickers);
+    }
+
+    if counts == [3, 2] {
+        return (HandRank::FullHouse, kickers);
+    }
+
+    if is_flush {
+        return (HandRank::Flush, kickers);
+    }
+
+    if is_straight {
+        return (HandRank::Straight, vec![straight_high.unwrap()]);
+    }
+
+    if counts == [3, 1, 1] {
+        return (HandRank::ThreeOfAKind, kickers);
+    }
+
+    if counts == [2, 2, 1] {
+        return (HandRank::TwoPair, kickers);
+    }
+
+    if counts == [2, 1, 1, 1] {
+        return (HandRank::OnePair, kickers);
+    }
+
+    (HandRank::HighCard, kickers)
+}
+
+// Highest card of five consecutive distinct rank values, if any, treating
+// A-2-3-4-5 (the wheel) as a straight with Five as the high card.
+fn straight_high_card(distinct_values: &[u8]) -> Option<u8> {
+    if distinct_values.len() < 5 {
+        return None;
+    }
+
+    for window in distinct_values.windows(5) {
+        if window[4] - window[0] == 4 {
+            return Some(window[4]);
+        }
+    }
+
+    let is_wheel = [2, 3, 4, 5, 14]
+        .iter()
+        .all(|value| distinct_values.contains(value));
+
+    if is_wheel {
+        return Some(5);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(spec: &str) -> Vec<Card> {
+        spec.split_whitespace()
+            .map(|card| card.parse::<Card>().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn straight_flush_beats_flush() {
+        let (straight_flush, _) = classify(&cards("9h 10h Jh Qh Kh"));
+        let (flush, _) = classify(&cards("2h 5h 8h 10h Kh"));
+
+        assert_eq!(straight_flush, HandRank::StraightFlush);
+        assert_eq!(flush, HandRank::Flush);
+        assert!(straight_flush > flush);
+    }
+
+    #[test]
+    fn wheel_is_a_five_high_straight() {
+        let (rank, kickers) = classify(&cards("Ah 2c 3d 4s 5h"));
+
+        assert_eq!(rank, HandRank::Straight);
+        assert_eq!(kickers, vec![5]);
+    }
+
+    #[test]
+    fn four_of_a_kind_beats_full_house_with_same_top_rank() {
+        let (quads, quads_kickers) = classify(&cards("Ac Ad Ah As Kc"));
+        let (full_house, full_house_kickers) = classify(&cards("Ac Ad Ah Kc Kd"));
+
+        assert_eq!(quads, HandRank::FourOfAKind);
+        assert_eq!(quads_kickers, vec![14, 13]);
+
+        assert_eq!(full_house, HandRank::FullHouse);
+        assert_eq!(full_house_kickers, vec![14, 13]);
+
+        assert!(quads > full_house);
+    }
+
+    #[test]
+    fn best_hand_picks_the_strongest_five_of_seven_cards() {
+        let seven_cards = cards("2h 7c Kd 9s Ah Ac Kc");
+        let (rank, kickers) = best_hand(&seven_cards).unwrap();
+
+        assert_eq!(rank, HandRank::TwoPair);
+        assert_eq!(kickers, vec![14, 13, 9]);
+    }
+
+    #[test]
+    fn best_hand_requires_at_least_five_cards() {
+        assert_eq!(best_hand(&cards("Ah Kh")), None);
+    }
+
+    #[test]
+    fn best_hand_constrained_cannot_use_a_board_only_straight_flush() {
+        // The board alone is a straight flush, but Omaha requires exactly
+        // two hole cards and exactly three board cards, so a player can
+        // never be credited with a hand made purely from the board.
+        let hole = cards("2c 2d 2h 2s");
+        let board = cards("9h 10h Jh Qh Kh");
+
+        let (rank, _) = best_hand_constrained(&hole, &board).unwrap();
+
+        assert_eq!(rank, HandRank::OnePair);
+    }
+
+    #[test]
+    fn best_hand_constrained_requires_enough_hole_and_board_cards() {
+        assert_eq!(
+            best_hand_constrained(&cards("Ah"), &cards("2c 7d 9s")),
+            None
+        );
+        assert_eq!(
+            best_hand_constrained(&cards("Ah Kh Qc Jc"), &cards("2c 7d")),
+            None
+        );
+    }
+}
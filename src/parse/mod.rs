@@ -1,16 +1,25 @@
+pub mod eval;
+pub mod stats;
+
+use std::cmp::Ordering;
+use std::fmt;
 use std::num::ParseIntError;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
 
 use regex::Regex;
 
 use serde::{Deserialize, Serialize};
 
+use eval::Showdown;
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Hand {
     pub game: Game,
     pub stake: Amount,
     pub seats: Vec<Seat>,
     pub actions: Vec<Action>,
+    pub showdown: Vec<Showdown>,
 }
 
 impl Hand {
@@ -20,6 +29,7 @@ impl Hand {
             stake: Amount::default(),
             seats: vec![],
             actions: vec![],
+            showdown: vec![],
         }
     }
 }
@@ -37,6 +47,23 @@ impl Amount {
             fraction: 0,
         }
     }
+
+    /// Adds two amounts, carrying the fraction (assumed to be cents) into
+    /// the integer part.
+    pub fn add(self, other: Amount) -> Amount {
+        let mut integer = self.integer + other.integer;
+        let mut fraction = self.fraction as u32 + other.fraction as u32;
+
+        if fraction >= 100 {
+            integer += 1;
+            fraction -= 100;
+        }
+
+        Amount {
+            integer,
+            fraction: fraction as u8,
+        }
+    }
 }
 
 impl FromStr for Amount {
@@ -71,6 +98,8 @@ pub enum Game {
     Unknown(String),
     NoLimitHoldem,
     NoLimitHoldemHeadsUp,
+    PotLimitOmaha,
+    PotLimitOmahaHeadsUp,
 }
 
 impl Game {
@@ -79,6 +108,10 @@ impl Game {
             Game::NoLimitHoldem
         } else if name.eq("Holdem (1 on 1)  No Limit") {
             Game::NoLimitHoldemHeadsUp
+        } else if name.eq("Omaha  Pot Limit") {
+            Game::PotLimitOmaha
+        } else if name.eq("Omaha (1 on 1)  Pot Limit") {
+            Game::PotLimitOmahaHeadsUp
         } else {
             Game::Unknown(String::from(name))
         }
@@ -87,9 +120,9 @@ impl Game {
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Seat {
-    number: u8,
-    player_id: String,
-    stack: Amount,
+    pub number: u8,
+    pub player_id: String,
+    pub stack: Amount,
 }
 
 impl Seat {
@@ -102,27 +135,62 @@ impl Seat {
     }
 }
 
+// Internally tagged with named fields so the JSON output is a stable,
+// documented schema (e.g. `{"type":"raise","player":"p1","raiseBy":..}`)
+// rather than serde's default externally-tagged, positional representation.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase", rename_all_fields = "camelCase")]
 pub enum Action {
     // Player actions
-    Bet(String, Amount),
-    Call(String, Amount),
-    Check(String),
-    Collect(String, Amount),
-    Fold(String),
-    Muck(String),
-    Post(String, Amount),
-    Raise(String, Amount, Amount),
-    Show(String, Card, Card),
+    Bet {
+        player: String,
+        amount: Amount,
+    },
+    Call {
+        player: String,
+        amount: Amount,
+    },
+    Check {
+        player: String,
+    },
+    Collect {
+        player: String,
+        amount: Amount,
+    },
+    Fold {
+        player: String,
+    },
+    Muck {
+        player: String,
+    },
+    Post {
+        player: String,
+        amount: Amount,
+    },
+    Raise {
+        player: String,
+        raise_by: Amount,
+        raise_to: Amount,
+    },
+    Show {
+        player: String,
+        cards: Vec<Card>,
+    },
 
     // Dealer actions
     PreFlop,
-    Flop(Card, Card, Card),
-    Turn(Card),
-    River(Card),
+    Flop {
+        cards: [Card; 3],
+    },
+    Turn {
+        card: Card,
+    },
+    River {
+        card: Card,
+    },
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub struct Card {
     pub rank: Rank,
     pub suit: Suit,
@@ -134,6 +202,21 @@ impl Card {
     }
 }
 
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.rank, self.suit)
+    }
+}
+
+// `{:?}`/`{:#?}` (e.g. the `--debug` dump in `main.rs`) should read like a
+// real card ("Ah") rather than the derived field-by-field form
+// (`Card { rank: Ace, suit: Heart }`), so `Debug` just delegates to `Display`.
+impl fmt::Debug for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
 impl FromStr for Card {
     type Err = CardParseError;
 
@@ -155,7 +238,7 @@ impl FromStr for Card {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CardParseError;
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Rank {
     Ace,
     King,
@@ -172,6 +255,62 @@ pub enum Rank {
     Two,
 }
 
+impl Rank {
+    /// Numeric value used for ordering (Two is lowest at 2, Ace is highest
+    /// at 14).
+    pub fn value(&self) -> u8 {
+        match self {
+            Rank::Two => 2,
+            Rank::Three => 3,
+            Rank::Four => 4,
+            Rank::Five => 5,
+            Rank::Six => 6,
+            Rank::Seven => 7,
+            Rank::Eight => 8,
+            Rank::Nine => 9,
+            Rank::Ten => 10,
+            Rank::Jack => 11,
+            Rank::Queen => 12,
+            Rank::King => 13,
+            Rank::Ace => 14,
+        }
+    }
+}
+
+impl PartialOrd for Rank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            Rank::Ace => "A",
+            Rank::King => "K",
+            Rank::Queen => "Q",
+            Rank::Jack => "J",
+            Rank::Ten => "10",
+            Rank::Nine => "9",
+            Rank::Eight => "8",
+            Rank::Seven => "7",
+            Rank::Six => "6",
+            Rank::Five => "5",
+            Rank::Four => "4",
+            Rank::Three => "3",
+            Rank::Two => "2",
+        };
+
+        write!(f, "{}", symbol)
+    }
+}
+
 impl FromStr for Rank {
     type Err = CardParseError;
 
@@ -197,7 +336,7 @@ impl FromStr for Rank {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Suit {
     Club,
     Diamond,
@@ -205,6 +344,41 @@ pub enum Suit {
     Spade,
 }
 
+// Whether `Suit`'s `Display` impl renders Unicode suit glyphs (♣ ♦ ♥ ♠)
+// instead of the default single-letter ASCII form ("c", "d", "h", "s"). This
+// repo has no `Cargo.toml` (so there's no `[features]` table to gate a
+// compile-time flag against); a CLI flag flips this at startup instead. See
+// `set_unicode_suits`.
+static UNICODE_SUITS: AtomicBool = AtomicBool::new(false);
+
+/// Sets whether `Suit`'s `Display` impl renders Unicode suit glyphs. Intended
+/// to be called once at startup from a CLI flag.
+pub fn set_unicode_suits(enabled: bool) {
+    UNICODE_SUITS.store(enabled, AtomicOrdering::Relaxed);
+}
+
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = if UNICODE_SUITS.load(AtomicOrdering::Relaxed) {
+            match self {
+                Suit::Club => "♣",
+                Suit::Diamond => "♦",
+                Suit::Heart => "♥",
+                Suit::Spade => "♠",
+            }
+        } else {
+            match self {
+                Suit::Club => "c",
+                Suit::Diamond => "d",
+                Suit::Heart => "h",
+                Suit::Spade => "s",
+            }
+        };
+
+        write!(f, "{}", symbol)
+    }
+}
+
 impl FromStr for Suit {
     type Err = CardParseError;
 
@@ -240,8 +414,7 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
     let post_re = Regex::new(r"(?P<player_id>.+) - Posts .+ \$(?P<amount>.+)").unwrap();
     let raise_re =
         Regex::new(r"(?P<player_id>.+) - Raises \$(?P<raise>.+) to \$(?P<total>.+)").unwrap();
-    let show_re =
-        Regex::new(r"(?P<player_id>.+) - Shows \[(?P<card_1>.+) (?P<card_2>.+)\]").unwrap();
+    let show_re = Regex::new(r"(?P<player_id>.+) - Shows \[(?P<cards>.+)\]").unwrap();
 
     let preflop_re = Regex::new(r"\*\*\* POCKET CARDS \*\*\*").unwrap();
     let flop_re =
@@ -291,7 +464,10 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
                 let amount = captures.name("amount").unwrap().as_str();
                 let amount = amount.parse::<Amount>().unwrap();
 
-                let action = Action::Bet(String::from(player_id), amount);
+                let action = Action::Bet {
+                    player: String::from(player_id),
+                    amount,
+                };
 
                 current_hand.actions.push(action);
 
@@ -306,7 +482,10 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
                 let amount = captures.name("amount").unwrap().as_str();
                 let amount = amount.parse::<Amount>().unwrap();
 
-                let action = Action::Call(String::from(player_id), amount);
+                let action = Action::Call {
+                    player: String::from(player_id),
+                    amount,
+                };
 
                 current_hand.actions.push(action);
 
@@ -318,7 +497,9 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
             None => (),
             Some(captures) => {
                 let player_id = captures.name("player_id").unwrap().as_str();
-                let action = Action::Check(String::from(player_id));
+                let action = Action::Check {
+                    player: String::from(player_id),
+                };
 
                 current_hand.actions.push(action);
 
@@ -333,7 +514,10 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
                 let amount = captures.name("amount").unwrap().as_str();
                 let amount = amount.parse::<Amount>().unwrap();
 
-                let action = Action::Collect(String::from(player_id), amount);
+                let action = Action::Collect {
+                    player: String::from(player_id),
+                    amount,
+                };
 
                 current_hand.actions.push(action);
 
@@ -345,7 +529,9 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
             None => (),
             Some(captures) => {
                 let player_id = captures.name("player_id").unwrap().as_str();
-                let action = Action::Fold(String::from(player_id));
+                let action = Action::Fold {
+                    player: String::from(player_id),
+                };
 
                 current_hand.actions.push(action);
 
@@ -357,7 +543,9 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
             None => (),
             Some(captures) => {
                 let player_id = captures.name("player_id").unwrap().as_str();
-                let action = Action::Muck(String::from(player_id));
+                let action = Action::Muck {
+                    player: String::from(player_id),
+                };
 
                 current_hand.actions.push(action);
 
@@ -372,7 +560,10 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
                 let amount = captures.name("amount").unwrap().as_str();
                 let amount = amount.parse::<Amount>().unwrap();
 
-                let action = Action::Post(String::from(player_id), amount);
+                let action = Action::Post {
+                    player: String::from(player_id),
+                    amount,
+                };
 
                 current_hand.actions.push(action);
 
@@ -391,7 +582,11 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
                 let total = captures.name("total").unwrap().as_str();
                 let total = total.parse::<Amount>().unwrap();
 
-                let action = Action::Raise(String::from(player_id), raise, total);
+                let action = Action::Raise {
+                    player: String::from(player_id),
+                    raise_by: raise,
+                    raise_to: total,
+                };
 
                 current_hand.actions.push(action);
 
@@ -404,13 +599,16 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
             Some(captures) => {
                 let player_id = captures.name("player_id").unwrap().as_str();
 
-                let card_1 = captures.name("card_1").unwrap().as_str();
-                let card_1 = card_1.parse::<Card>().unwrap();
-
-                let card_2 = captures.name("card_2").unwrap().as_str();
-                let card_2 = card_2.parse::<Card>().unwrap();
+                let cards = captures.name("cards").unwrap().as_str();
+                let cards = cards
+                    .split_whitespace()
+                    .map(|card| card.parse::<Card>().unwrap())
+                    .collect();
 
-                let action = Action::Show(String::from(player_id), card_1, card_2);
+                let action = Action::Show {
+                    player: String::from(player_id),
+                    cards,
+                };
 
                 current_hand.actions.push(action);
 
@@ -435,7 +633,9 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
                 let card_3 = captures.name("card_3").unwrap().as_str();
                 let card_3 = card_3.parse::<Card>().unwrap();
 
-                let action = Action::Flop(card_1, card_2, card_3);
+                let action = Action::Flop {
+                    cards: [card_1, card_2, card_3],
+                };
 
                 current_hand.actions.push(action);
 
@@ -449,7 +649,7 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
                 let card = captures.name("card").unwrap().as_str();
                 let card = card.parse::<Card>().unwrap();
 
-                let action = Action::Turn(card);
+                let action = Action::Turn { card };
 
                 current_hand.actions.push(action);
 
@@ -463,7 +663,7 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
                 let card = captures.name("card").unwrap().as_str();
                 let card = card.parse::<Card>().unwrap();
 
-                let action = Action::River(card);
+                let action = Action::River { card };
 
                 current_hand.actions.push(action);
 
@@ -472,12 +672,14 @@ pub fn parse(input_file: &str) -> Vec<Hand> {
         };
 
         if line.trim().len() == 0 && current_hand.seats.len() != 0 {
+            current_hand.showdown = eval::evaluate(&current_hand);
             hands.push(current_hand.clone());
             current_hand = Hand::default();
         }
     }
 
     if current_hand.seats.len() != 0 {
+        current_hand.showdown = eval::evaluate(&current_hand);
         hands.push(current_hand.clone());
     }
 
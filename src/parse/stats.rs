@@ -0,0 +1,282 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::Serialize;
+
+use super::{Action, Amount, Hand};
+
+/// Cross-hand aggregate statistics for a single player, built by replaying
+/// every `Hand`'s action list.
+#[derive(Clone, Debug, Serialize)]
+pub struct PlayerStats {
+    pub player_id: String,
+    pub hands_played: u32,
+    pub voluntarily_put_money_in_pot: u32,
+    pub preflop_raises: u32,
+    pub showdowns_reached: u32,
+
+    /// Net chips won across all hands, in cents (negative means a net
+    /// loss). `Amount` has no sign, so cents are used here instead.
+    pub net_cents: i64,
+}
+
+impl PlayerStats {
+    fn new(player_id: String) -> PlayerStats {
+        PlayerStats {
+            player_id,
+            hands_played: 0,
+            voluntarily_put_money_in_pot: 0,
+            preflop_raises: 0,
+            showdowns_reached: 0,
+            net_cents: 0,
+        }
+    }
+
+    /// Voluntarily-put-money-in-pot percentage.
+    pub fn vpip(&self) -> f64 {
+        percentage(self.voluntarily_put_money_in_pot, self.hands_played)
+    }
+
+    /// Preflop-raise percentage.
+    pub fn pfr(&self) -> f64 {
+        percentage(self.preflop_raises, self.hands_played)
+    }
+}
+
+fn percentage(count: u32, total: u32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64 * 100.0
+    }
+}
+
+fn to_cents(amount: &Amount) -> i64 {
+    amount.integer as i64 * 100 + amount.fraction as i64
+}
+
+/// Aggregates per-player statistics over every hand, keyed by
+/// `Seat::player_id`.
+pub fn aggregate(hands: &[Hand]) -> HashMap<String, PlayerStats> {
+    let mut stats: HashMap<String, PlayerStats> = HashMap::new();
+
+    for hand in hands {
+        for seat in &hand.seats {
+            stats
+                .entry(seat.player_id.clone())
+                .or_insert_with(|| PlayerStats::new(seat.player_id.clone()))
+                .hands_played += 1;
+        }
+
+        let mut preflop = true;
+        let mut vpip_this_hand: HashSet<String> = HashSet::new();
+        let mut pfr_this_hand: HashSet<String> = HashSet::new();
+
+        // Each player's total contribution on the current street, in
+        // cents, so a raise can be charged `raise_to - already_committed`
+        // instead of just the increment over the previous bet.
+        let mut committed_this_street: HashMap<String, i64> = HashMap::new();
+
+        for action in &hand.actions {
+            match action {
+                Action::Flop { .. } | Action::Turn { .. } | Action::River { .. } => {
+                    preflop = false;
+                    committed_this_street.clear();
+                }
+                Action::Bet { player, amount } | Action::Call { player, amount } => {
+                    if preflop {
+                        vpip_this_hand.insert(player.clone());
+                    }
+
+                    let contribution = to_cents(amount);
+                    *committed_this_street.entry(player.clone()).or_insert(0) += contribution;
+
+                    add_net(&mut stats, player, -contribution);
+                }
+                Action::Raise { player, raise_to, .. } => {
+                    if preflop {
+                        vpip_this_hand.insert(player.clone());
+                        pfr_this_hand.insert(player.clone());
+                    }
+
+                    let already_committed =
+                        committed_this_street.get(player).copied().unwrap_or(0);
+                    let total_committed = to_cents(raise_to);
+                    let contribution = total_committed - already_committed;
+
+                    committed_this_street.insert(player.clone(), total_committed);
+
+                    add_net(&mut stats, player, -contribution);
+                }
+                Action::Post { player, amount } => {
+                    let contribution = to_cents(amount);
+                    *committed_this_street.entry(player.clone()).or_insert(0) += contribution;
+
+                    add_net(&mut stats, player, -contribution);
+                }
+                Action::Collect { player, amount } => add_net(&mut stats, player, to_cents(amount)),
+                _ => (),
+            }
+        }
+
+        for player_id in vpip_this_hand {
+            if let Some(player) = stats.get_mut(&player_id) {
+                player.voluntarily_put_money_in_pot += 1;
+            }
+        }
+
+        for player_id in pfr_this_hand {
+            if let Some(player) = stats.get_mut(&player_id) {
+                player.preflop_raises += 1;
+            }
+        }
+
+        for showdown in &hand.showdown {
+            if let Some(player) = stats.get_mut(&showdown.player_id) {
+                player.showdowns_reached += 1;
+            }
+        }
+    }
+
+    stats
+}
+
+fn add_net(stats: &mut HashMap<String, PlayerStats>, player: &str, delta_cents: i64) {
+    if let Some(player) = stats.get_mut(player) {
+        player.net_cents += delta_cents;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{Card, Game, Seat};
+
+    fn amount(integer: u32, fraction: u8) -> Amount {
+        Amount { integer, fraction }
+    }
+
+    fn seat(number: u8, player_id: &str) -> Seat {
+        Seat {
+            number,
+            player_id: String::from(player_id),
+            stack: amount(100, 0),
+        }
+    }
+
+    fn hand(actions: Vec<Action>) -> Hand {
+        Hand {
+            game: Game::NoLimitHoldem,
+            stake: amount(1, 0),
+            seats: vec![seat(1, "p1"), seat(2, "p2")],
+            actions,
+            showdown: vec![],
+        }
+    }
+
+    fn card(spec: &str) -> Card {
+        spec.parse().unwrap()
+    }
+
+    #[test]
+    fn raise_charges_the_delta_to_reach_raise_to_not_just_raise_by() {
+        // p1 already posted $1, then raises "$8 to $10" -- the charge for
+        // the raise itself should be the remaining $9, for a total preflop
+        // outlay of $10, not $1 (post) + $8 (raise_by) = $9.
+        let hands = vec![hand(vec![
+            Action::Post {
+                player: String::from("p1"),
+                amount: amount(1, 0),
+            },
+            Action::Post {
+                player: String::from("p2"),
+                amount: amount(2, 0),
+            },
+            Action::Raise {
+                player: String::from("p1"),
+                raise_by: amount(8, 0),
+                raise_to: amount(10, 0),
+            },
+        ])];
+
+        let stats = aggregate(&hands);
+
+        assert_eq!(stats["p1"].net_cents, -1000);
+    }
+
+    #[test]
+    fn committed_amount_resets_between_streets() {
+        let hands = vec![hand(vec![
+            Action::Post {
+                player: String::from("p1"),
+                amount: amount(1, 0),
+            },
+            Action::Post {
+                player: String::from("p2"),
+                amount: amount(2, 0),
+            },
+            Action::Raise {
+                player: String::from("p1"),
+                raise_by: amount(8, 0),
+                raise_to: amount(10, 0),
+            },
+            Action::Call {
+                player: String::from("p2"),
+                amount: amount(8, 0),
+            },
+            Action::Flop {
+                cards: [card("2c"), card("7d"), card("9h")],
+            },
+            Action::Bet {
+                player: String::from("p1"),
+                amount: amount(5, 0),
+            },
+            Action::Call {
+                player: String::from("p2"),
+                amount: amount(5, 0),
+            },
+            Action::Collect {
+                player: String::from("p1"),
+                amount: amount(30, 0),
+            },
+        ])];
+
+        let stats = aggregate(&hands);
+
+        // If the flop bet/call were charged on top of a preflop tally that
+        // never reset, the raise-delta math above would double count.
+        assert_eq!(stats["p1"].net_cents, 1500);
+        assert_eq!(stats["p2"].net_cents, -1500);
+    }
+
+    #[test]
+    fn vpip_and_pfr_only_count_voluntary_preflop_actions() {
+        let hands = vec![hand(vec![
+            Action::Post {
+                player: String::from("p1"),
+                amount: amount(1, 0),
+            },
+            Action::Post {
+                player: String::from("p2"),
+                amount: amount(2, 0),
+            },
+            Action::Raise {
+                player: String::from("p1"),
+                raise_by: amount(8, 0),
+                raise_to: amount(10, 0),
+            },
+            Action::Fold {
+                player: String::from("p2"),
+            },
+        ])];
+
+        let stats = aggregate(&hands);
+
+        assert_eq!(stats["p1"].hands_played, 1);
+        assert_eq!(stats["p1"].vpip(), 100.0);
+        assert_eq!(stats["p1"].pfr(), 100.0);
+
+        // Posting the blinds isn't a voluntary action.
+        assert_eq!(stats["p2"].vpip(), 0.0);
+        assert_eq!(stats["p2"].pfr(), 0.0);
+    }
+}